@@ -7,37 +7,123 @@ pub enum ParseError {
 
     #[error("Failed to compile Glob pattern")]
     Glob(#[from] globset::Error),
+
+    #[error("Invalid owner {token:?}")]
+    InvalidOwner { token: String },
+
+    #[error("Missing pattern in input {input:?}")]
+    MissingPattern { input: String },
+}
+
+/// The standard GitHub locations a CODEOWNERS file may live in, in precedence order.
+pub const CODEOWNERS_LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Errors from `Codeowners::from_repo`.
+#[derive(Error, Debug)]
+pub enum LoadError {
+    #[error("No CODEOWNERS file found at any of: {}", .searched.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", "))]
+    NotFound { searched: Vec<std::path::PathBuf> },
+
+    #[error("Failed to read {path}", path = .path.display())]
+    Io {
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Failed to parse {origin}", origin = .origin.display())]
+    Parse {
+        origin: std::path::PathBuf,
+        source: ParseError,
+    },
 }
 
-/// Represents one Codeowner as either a GitHub handle via an Email address.
-///
-/// For now it is assumed that all those values that aren't GitHub handles are email addresses.
-#[derive(Debug, PartialEq, Eq)]
+/// Represents one Codeowner as a GitHub user handle, a team handle, or an email address.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Owner {
     Email(String),
     Handle(String),
+    Team { org: String, team: String },
+}
+
+/// GitHub handles are limited to alphanumerics and hyphens, and may not start or end with one.
+fn is_valid_handle_component(component: &str) -> bool {
+    !component.is_empty()
+        && !component.starts_with('-')
+        && !component.ends_with('-')
+        && component.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// A deliberately loose email syntax check: one `@`, non-empty local/domain parts, a `.` in the domain.
+fn looks_like_email(token: &str) -> bool {
+    match token.split_once('@') {
+        Some((local, domain)) => !local.is_empty() && !domain.contains('@') && domain.contains('.'),
+        None => false,
+    }
 }
 
 impl Owner {
-    pub fn parse(input: impl AsRef<str>) -> Owner {
-        let input = input.as_ref();
-        if input.starts_with('@') {
-            Owner::Handle(input.to_string())
+    pub fn parse(input: impl AsRef<str>) -> Result<Owner, ParseError> {
+        let token = input.as_ref();
+        if let Some(handle) = token.strip_prefix('@') {
+            return match handle.split_once('/') {
+                Some((org, team))
+                    if is_valid_handle_component(org) && is_valid_handle_component(team) =>
+                {
+                    Ok(Owner::Team {
+                        org: org.to_string(),
+                        team: team.to_string(),
+                    })
+                }
+                None if is_valid_handle_component(handle) => Ok(Owner::Handle(token.to_string())),
+                _ => Err(ParseError::InvalidOwner {
+                    token: token.to_string(),
+                }),
+            };
+        }
+        if looks_like_email(token) {
+            Ok(Owner::Email(token.to_string()))
         } else {
-            Owner::Email(input.to_string())
+            Err(ParseError::InvalidOwner {
+                token: token.to_string(),
+            })
         }
     }
 }
 
 impl std::fmt::Display for Owner {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        (match self {
-            Self::Email(mail) => mail,
-            Self::Handle(name) => name,
-        }).fmt(fmt)
+        match self {
+            Self::Email(mail) => mail.fmt(fmt),
+            Self::Handle(name) => name.fmt(fmt),
+            Self::Team { org, team } => write!(fmt, "@{}/{}", org, team),
+        }
     }
 }
 
+/// Split a rule line into whitespace-separated fields, honoring `\ ` and `\#` as escaped literals.
+fn tokenize_rule_line(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if matches!(chars.peek(), Some(' ') | Some('#')) => {
+                current.push(chars.next().expect("peeked"));
+            }
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
 /// Convert a Codeowners pattern into a glob pattern
 fn pattern_to_glob(pattern: impl AsRef<str>) -> impl Iterator<Item = String> {
     let pattern = pattern.as_ref();
@@ -67,12 +153,87 @@ fn pattern_to_glob(pattern: impl AsRef<str>) -> impl Iterator<Item = String> {
     std::iter::once(pattern).chain(std::iter::once(subdirectory_pattern))
 }
 
+/// The longest leading run of literal path components before the first glob metacharacter, used
+/// to prune tree-walks; only `/`-anchored patterns get one, since others match at any depth.
+fn pattern_base(pattern: &str) -> std::path::PathBuf {
+    if !pattern.starts_with('/') {
+        return std::path::PathBuf::new();
+    }
+    let trimmed = pattern.trim_start_matches('/');
+    let mut base = std::path::PathBuf::new();
+    for component in trimmed.split('/') {
+        if component.is_empty() || component.contains(['*', '?', '[']) {
+            break;
+        }
+        base.push(component);
+    }
+    base
+}
+
+/// A GitLab-style CODEOWNERS section: `[Name]`, `^[Name]` (optional), or `[Name][2]` (2 approvals).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    pub name: String,
+    pub optional: bool,
+    pub required_approvals: Option<u32>,
+    /// Owners applied to rules in this section that don't list any owners of their own.
+    pub default_owners: Vec<Owner>,
+}
+
+/// Parse a GitLab section header line. Returns `Ok(None)` if `line` isn't one, e.g. a glob
+/// character class like `[Mm]akefile @owner` that merely starts with `[`.
+fn parse_section_header(line: &str) -> Result<Option<Section>, ParseError> {
+    let (optional, rest) = match line.strip_prefix('^') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+    let Some(rest) = rest.strip_prefix('[') else {
+        return Ok(None);
+    };
+    let Some(name_end) = rest.find(']') else {
+        return Ok(None);
+    };
+    let name = rest[..name_end].trim().to_string();
+    let mut rest = &rest[name_end + 1..];
+
+    let mut required_approvals = None;
+    if let Some(after_bracket) = rest.strip_prefix('[') {
+        let Some(count_end) = after_bracket.find(']') else {
+            return Ok(None);
+        };
+        required_approvals = after_bracket[..count_end].trim().parse::<u32>().ok();
+        rest = &after_bracket[count_end + 1..];
+    }
+
+    // an invalid owner token means this wasn't a section header after all
+    let mut default_owners = Vec::with_capacity(4);
+    for token in tokenize_rule_line(rest) {
+        match Owner::parse(&token) {
+            Ok(owner) => default_owners.push(owner),
+            Err(_) => return Ok(None),
+        }
+    }
+
+    Ok(Some(Section {
+        name,
+        optional,
+        required_approvals,
+        default_owners,
+    }))
+}
+
 /// Representation of one Codeowner pattern and the respective list of owners.
 #[derive(Debug)]
 pub struct Rule {
     pub pattern: String,
     pub owners: Vec<Owner>,
     pub matchers: Vec<globset::GlobMatcher>,
+    /// Static base-directory prefix of `pattern`, see `pattern_base`.
+    pub(crate) base: std::path::PathBuf,
+    /// Whether this rule was written with a leading `!`, un-owning rather than owning a path.
+    pub negated: bool,
+    /// Index into `Codeowners::sections` of the section this rule was declared under.
+    pub section: usize,
 }
 
 impl PartialEq for Rule {
@@ -80,35 +241,69 @@ impl PartialEq for Rule {
         /* We purposefully don't want to compare the glob, as it is
          * uniquely determined by the pattern
          */
-        self.pattern == other.pattern && self.owners == other.owners
+        self.pattern == other.pattern
+            && self.owners == other.owners
+            && self.negated == other.negated
+            && self.section == other.section
     }
 }
 
 impl Rule {
     pub fn parse(input: impl AsRef<str>) -> Result<Rule, ParseError> {
+        Self::parse_in_section(input, &[], 0)
+    }
+
+    /// Parse a rule declared under a section, falling back to that section's `default_owners`
+    /// when the line itself lists none.
+    fn parse_in_section(
+        input: impl AsRef<str>,
+        default_owners: &[Owner],
+        section: usize,
+    ) -> Result<Rule, ParseError> {
         let input = input.as_ref();
-        // split in spaces and ignore multiple spaces
-        // TODO add support for spaces in paths
-        let parts = input
-            .split(' ')
-            .filter(|part| !part.is_empty())
-            .collect::<Vec<&str>>();
-        if parts.len() < 2 {
+        let parts = tokenize_rule_line(input);
+        if parts.is_empty() {
+            return Err(ParseError::MissingOwners {
+                input: input.to_string(),
+            });
+        }
+        let (negated, raw_pattern) = match parts[0].strip_prefix('!') {
+            Some(rest) => (true, rest.to_string()),
+            None => (false, parts[0].clone()),
+        };
+        if raw_pattern.is_empty() {
+            return Err(ParseError::MissingPattern {
+                input: input.to_string(),
+            });
+        }
+        let pattern = raw_pattern;
+        let mut owners: Vec<Owner> = parts[1..]
+            .iter()
+            .map(Owner::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        if owners.is_empty() && !negated {
+            owners = default_owners.to_vec();
+        }
+        // a negated rule only ever un-owns paths a previous rule already claimed, so it
+        // doesn't need owners of its own
+        if owners.is_empty() && !negated {
             return Err(ParseError::MissingOwners {
                 input: input.to_string(),
             });
         }
-        let pattern = parts[0].to_string();
-        let owners = parts[1..].iter().map(Owner::parse).collect();
         let matchers = pattern_to_glob(&pattern)
             .map(|pattern| globset::Glob::new(&pattern))
             .map(|glob| glob.map(|glob| glob.compile_matcher()))
             .collect::<Result<Vec<_>, _>>()?;
+        let base = pattern_base(&pattern);
 
         Ok(Rule {
           matchers,
           pattern,
-          owners
+          owners,
+          base,
+          negated,
+          section,
         })
     }
 }
@@ -117,22 +312,140 @@ impl Rule {
 #[derive(Debug)]
 pub struct Codeowners {
     pub rules: Vec<Rule>,
+    /// Sections rules are grouped under; always has at least the implicit, unnamed root section.
+    pub sections: Vec<Section>,
+    /// All rules' glob matchers, combined into a single set for a one-shot lookup per path.
+    glob_set: globset::GlobSet,
+    /// Maps each glob index in `glob_set` back to the owning `Rule`'s index in `rules`.
+    glob_rule_indices: Vec<usize>,
 }
 
 impl PartialEq for Codeowners {
     fn eq(&self, other: &Codeowners) -> bool {
-        self.rules == other.rules
+        self.rules == other.rules && self.sections == other.sections
     }
 }
 
 impl Codeowners {
+    /// Locate and parse the CODEOWNERS file for the repository rooted at `repo_root`.
+    pub fn from_repo(repo_root: &std::path::Path) -> Result<Codeowners, LoadError> {
+        for location in CODEOWNERS_LOCATIONS {
+            let path = repo_root.join(location);
+            match std::fs::read_to_string(&path) {
+                Ok(content) => return Self::from_content(content, &path),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(LoadError::Io { path, source: err }),
+            }
+        }
+        Err(LoadError::NotFound {
+            searched: CODEOWNERS_LOCATIONS
+                .iter()
+                .map(|location| repo_root.join(location))
+                .collect(),
+        })
+    }
+
+    /// Parse CODEOWNERS content a caller already fetched from `origin`, e.g. over the GitHub API.
+    pub fn from_content(
+        content: impl AsRef<str>,
+        origin: &std::path::Path,
+    ) -> Result<Codeowners, LoadError> {
+        parse(content.as_ref()).map_err(|source| LoadError::Parse {
+            origin: origin.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Negation-aware last-match-wins winner rule index per section, see `matches`/`matches_all`.
+    fn section_winners(&self, path: &std::path::Path) -> Vec<Option<usize>> {
+        let mut rule_indices: Vec<usize> = self
+            .glob_set
+            .matches(path)
+            .into_iter()
+            .map(|glob_index| self.glob_rule_indices[glob_index])
+            .collect();
+        rule_indices.sort_unstable();
+        rule_indices.dedup();
+
+        let mut winners: Vec<Option<usize>> = vec![None; self.sections.len()];
+        for rule_index in rule_indices {
+            let rule = &self.rules[rule_index];
+            winners[rule.section] = if rule.negated { None } else { Some(rule_index) };
+        }
+        winners
+    }
+
     /// Match the given path against the set of rules and return the matching rules or None.
-    pub fn matches<'a, T: AsRef<std::path::Path>>(&'a self, path: T) -> Option<&'a [Owner]> {
-        let path = path.as_ref();
-        self.rules.iter()
-            .rev()
-            .find(|rule| rule.matchers.iter().any(|matcher| matcher.is_match(path)))
-            .map(|rule| rule.owners.as_slice())
+    pub fn matches<T: AsRef<std::path::Path>>(&self, path: T) -> Option<&[Owner]> {
+        self.section_winners(path.as_ref())
+            .into_iter()
+            .flatten()
+            .max()
+            .map(|rule_index| self.rules[rule_index].owners.as_slice())
+    }
+
+    /// Like `matches`, but resolved independently per section instead of picking one overall winner.
+    pub fn matches_all<T: AsRef<std::path::Path>>(&self, path: T) -> Vec<(&Section, &[Owner])> {
+        self.section_winners(path.as_ref())
+            .into_iter()
+            .enumerate()
+            .filter_map(|(section_index, winner)| {
+                winner.map(|rule_index| {
+                    (
+                        &self.sections[section_index],
+                        self.rules[rule_index].owners.as_slice(),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Whether `dir` is on the path to (or already inside) some rule's base directory.
+    fn dir_may_contain_match(&self, dir: &std::path::Path) -> bool {
+        self.rules.iter().any(|rule| {
+            rule.base.as_os_str().is_empty()
+                || dir.starts_with(&rule.base)
+                || rule.base.starts_with(dir)
+        })
+    }
+
+    /// Walk every file under `root` and pair it with its matching owners, if any.
+    pub fn owners_for_tree<'a>(
+        &'a self,
+        root: &std::path::Path,
+    ) -> impl Iterator<Item = (std::path::PathBuf, Option<&'a [Owner]>)> + 'a {
+        // needs `walkdir` added to Cargo.toml -- this tree ships without a manifest
+        let walk_root = root.to_path_buf();
+        walkdir::WalkDir::new(walk_root.clone())
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_file())
+            .map(move |entry| {
+                let relative = entry
+                    .path()
+                    .strip_prefix(&walk_root)
+                    .unwrap_or(entry.path())
+                    .to_path_buf();
+                // files in a directory no rule's base reaches are unowned without any glob lookup
+                let pruned = relative
+                    .parent()
+                    .map_or(false, |dir| !self.dir_may_contain_match(dir));
+                let owners = if pruned {
+                    None
+                } else {
+                    self.matches(std::path::Path::new("/").join(&relative))
+                };
+                (relative, owners)
+            })
+    }
+
+    /// Like `owners_for_tree`, but yields only the paths that no rule matched.
+    pub fn unowned<'a>(
+        &'a self,
+        root: &std::path::Path,
+    ) -> impl Iterator<Item = std::path::PathBuf> + 'a {
+        self.owners_for_tree(root)
+            .filter_map(|(path, owners)| if owners.is_none() { Some(path) } else { None })
     }
 }
 
@@ -147,12 +460,42 @@ pub fn parse(input: impl AsRef<str>) -> Result<Codeowners, ParseError> {
         .filter(|line| !line.is_empty())
         // ignore comments
         .filter(|line| !line.starts_with('#'));
-    // map all the remaining lines into Rule instances
-    let rules: Vec<_> = non_comment_lines_iterator
-        .map(Rule::parse)
-        .collect::<Result<Vec<_>, _>>()?;
 
-    Ok(Codeowners { rules })
+    // rules start out in the implicit, unnamed root section until a `[Section]` header is seen
+    let mut sections = vec![Section {
+        name: String::new(),
+        optional: false,
+        required_approvals: None,
+        default_owners: Vec::new(),
+    }];
+    let mut current_section = 0usize;
+    let mut rules = Vec::new();
+    for line in non_comment_lines_iterator {
+        if let Some(section) = parse_section_header(line)? {
+            sections.push(section);
+            current_section = sections.len() - 1;
+            continue;
+        }
+        let default_owners = sections[current_section].default_owners.clone();
+        rules.push(Rule::parse_in_section(line, &default_owners, current_section)?);
+    }
+
+    let mut glob_set_builder = globset::GlobSetBuilder::new();
+    let mut glob_rule_indices = Vec::new();
+    for (rule_index, rule) in rules.iter().enumerate() {
+        for matcher in &rule.matchers {
+            glob_set_builder.add(matcher.glob().clone());
+            glob_rule_indices.push(rule_index);
+        }
+    }
+    let glob_set = glob_set_builder.build()?;
+
+    Ok(Codeowners {
+        rules,
+        sections,
+        glob_set,
+        glob_rule_indices,
+    })
 }
 
 #[cfg(test)]
@@ -162,9 +505,7 @@ mod tests {
 
     #[test]
     fn more_test_on_nixpkgs() {
-        let codeowners = parse(
-            std::str::from_utf8(&std::fs::read("./CODEOWNERS").unwrap()).unwrap()
-        ).unwrap();
+        let codeowners = Codeowners::from_repo(std::path::Path::new(".")).unwrap();
         assert!(codeowners.matches("/lib").is_some());
         assert!(codeowners.matches("/lib/systems").is_some());
         assert!(codeowners.matches("/lib/foo").is_some());
@@ -284,17 +625,54 @@ apps/ @octocat
     #[test]
     fn test_parse_owner_email() {
         assert_eq!(
-            Owner::parse("something@something"),
-            Email("something@something".to_string())
+            Owner::parse("someone@example.com").unwrap(),
+            Email("someone@example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_owner_invalid_email() {
+        assert_eq!(
+            Owner::parse("not-an-email"),
+            Err(ParseError::InvalidOwner {
+                token: "not-an-email".to_string()
+            })
         );
     }
 
     #[test]
     fn test_parse_owner_handler() {
-        assert_eq!(Owner::parse("@someone"), Handle("@someone".to_string()));
         assert_eq!(
-            Owner::parse("@Org/someone"),
-            Handle("@Org/someone".to_string())
+            Owner::parse("@someone").unwrap(),
+            Handle("@someone".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_owner_team() {
+        assert_eq!(
+            Owner::parse("@Org/someone").unwrap(),
+            Team {
+                org: "Org".to_string(),
+                team: "someone".to_string(),
+            }
+        );
+        assert_eq!(Team { org: "Org".to_string(), team: "someone".to_string() }.to_string(), "@Org/someone");
+    }
+
+    #[test]
+    fn test_parse_owner_invalid_handle() {
+        assert_eq!(
+            Owner::parse("@-bad-handle-"),
+            Err(ParseError::InvalidOwner {
+                token: "@-bad-handle-".to_string()
+            })
+        );
+        assert_eq!(
+            Owner::parse("@org/-bad-team"),
+            Err(ParseError::InvalidOwner {
+                token: "@org/-bad-team".to_string()
+            })
         );
     }
 
@@ -309,10 +687,175 @@ apps/ @octocat
                     Owner::Handle("@user".to_owned()),
                     Owner::Email("someone@example.com".to_owned())
                 ],
+                base: std::path::PathBuf::new(),
+                negated: false,
+                section: 0,
             }
         );
     }
 
+    #[test]
+    fn test_parse_rule_with_escaped_space_in_pattern() {
+        let rule = Rule::parse("path\\ with\\ spaces/ @owner").unwrap();
+        assert_eq!(rule.pattern, "path with spaces/");
+        assert_eq!(rule.owners, vec![Handle("@owner".to_string())]);
+    }
+
+    #[test]
+    fn test_parse_rule_with_escaped_hash_in_pattern() {
+        let rule = Rule::parse("file\\#name @owner").unwrap();
+        assert_eq!(rule.pattern, "file#name");
+    }
+
+    #[test]
+    fn test_parse_rule_trailing_whitespace_tolerated() {
+        let rule = Rule::parse("some/path  @owner  \t ").unwrap();
+        assert_eq!(rule.pattern, "some/path");
+        assert_eq!(rule.owners, vec![Handle("@owner".to_string())]);
+    }
+
+    #[test]
+    fn test_parse_negated_rule() {
+        let rule = Rule::parse("!docs/generated/").unwrap();
+        assert!(rule.negated);
+        assert_eq!(rule.pattern, "docs/generated/");
+        assert!(rule.owners.is_empty());
+    }
+
+    #[test]
+    fn test_negated_rule_reexcludes_subpath() {
+        let codeowners = parse(
+            "docs/ @a\n\
+             !docs/generated/\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            codeowners.matches("docs/readme.md"),
+            Some([Owner::Handle("@a".to_string())].as_slice())
+        );
+        assert_eq!(codeowners.matches("docs/generated/output.html"), None);
+    }
+
+    #[test]
+    fn test_parse_bare_negation_rejected() {
+        assert_eq!(
+            Rule::parse("!"),
+            Err(ParseError::MissingPattern {
+                input: "!".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_section_header() {
+        assert_eq!(
+            parse_section_header("[Documentation]").unwrap(),
+            Some(Section {
+                name: "Documentation".to_string(),
+                optional: false,
+                required_approvals: None,
+                default_owners: vec![],
+            })
+        );
+        assert_eq!(
+            parse_section_header("^[Optional Review]").unwrap(),
+            Some(Section {
+                name: "Optional Review".to_string(),
+                optional: true,
+                required_approvals: None,
+                default_owners: vec![],
+            })
+        );
+        assert_eq!(
+            parse_section_header("[Two Reviewers][2] @default-owner").unwrap(),
+            Some(Section {
+                name: "Two Reviewers".to_string(),
+                optional: false,
+                required_approvals: Some(2),
+                default_owners: vec![Handle("@default-owner".to_string())],
+            })
+        );
+        assert_eq!(parse_section_header("docs/ @a").unwrap(), None);
+    }
+
+    #[test]
+    fn test_bracket_glob_pattern_not_mistaken_for_section_header() {
+        // `[Mm]akefile` is a glob character class, not a `[Section]` header
+        assert_eq!(parse_section_header("[Mm]akefile @owner").unwrap(), None);
+
+        let codeowners = parse("[Mm]akefile @owner\n").unwrap();
+        assert_eq!(codeowners.rules.len(), 1);
+        assert_eq!(codeowners.rules[0].pattern, "[Mm]akefile");
+        assert_eq!(codeowners.rules[0].owners, vec![Handle("@owner".to_string())]);
+        assert_eq!(codeowners.sections.len(), 1);
+        assert_eq!(
+            codeowners.matches("Makefile"),
+            Some([Handle("@owner".to_string())].as_slice())
+        );
+    }
+
+    #[test]
+    fn test_sections_own_independently() {
+        let codeowners = parse(
+            "docs/ @default-docs-owner\n\
+             \n\
+             [Documentation]\n\
+             docs/ @tech-writer\n\
+             \n\
+             [Two Reviewers][2]\n\
+             docs/ @reviewer-a @reviewer-b\n",
+        )
+        .unwrap();
+
+        assert_eq!(codeowners.sections.len(), 3);
+
+        let owned = codeowners.matches_all("docs/readme.md");
+        assert_eq!(owned.len(), 3);
+        assert_eq!(
+            owned[0],
+            (&codeowners.sections[0], [Handle("@default-docs-owner".to_string())].as_slice())
+        );
+        assert_eq!(
+            owned[1],
+            (&codeowners.sections[1], [Handle("@tech-writer".to_string())].as_slice())
+        );
+        assert_eq!(owned[2].0.required_approvals, Some(2));
+        assert_eq!(
+            owned[2].1,
+            [Handle("@reviewer-a".to_string()), Handle("@reviewer-b".to_string())].as_slice()
+        );
+    }
+
+    #[test]
+    fn test_negation_does_not_cross_sections() {
+        let codeowners = parse(
+            "* @a\n\
+             [Sec]\n\
+             !docs/\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            codeowners.matches("docs/x"),
+            Some([Handle("@a".to_string())].as_slice())
+        );
+    }
+
+    #[test]
+    fn test_section_default_owners_apply_to_bare_rules() {
+        let codeowners = parse(
+            "[Documentation] @tech-writer\n\
+             docs/\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            codeowners.matches("docs/readme.md"),
+            Some([Handle("@tech-writer".to_string())].as_slice())
+        );
+    }
+
     #[test]
     fn test_parse_invalid_rule() {
         assert_eq!(
@@ -322,4 +865,93 @@ apps/ @octocat
             })
         );
     }
+
+    /// A fresh, empty directory under the OS temp dir, removed when dropped.
+    struct TempDir(std::path::PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "codeowners-rs-test-{}-{}-{}",
+                name,
+                std::process::id(),
+                std::time::SystemTime::now()
+                    .duration_since(std::time::SystemTime::UNIX_EPOCH)
+                    .unwrap()
+                    .as_nanos()
+            ));
+            std::fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+
+        fn path(&self) -> &std::path::Path {
+            &self.0
+        }
+
+        fn write(&self, relative: &str, contents: &str) {
+            let path = self.0.join(relative);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_owners_for_tree_honors_non_absolute_pattern_at_any_depth() {
+        // `docs/*` matches at any depth, so `random/` must not be pruned
+        let dir = TempDir::new("depth");
+        dir.write("random/docs/readme.md", "");
+        dir.write("random/other/skip.md", "");
+
+        let codeowners = parse("docs/* @docs-owner\n").unwrap();
+
+        let owned: std::collections::BTreeMap<_, _> = codeowners
+            .owners_for_tree(dir.path())
+            .map(|(path, owners)| (path, owners.map(|owners| owners.to_vec())))
+            .collect();
+
+        assert_eq!(
+            owned.get(std::path::Path::new("random/docs/readme.md")),
+            Some(&Some(vec![Handle("@docs-owner".to_string())]))
+        );
+        assert_eq!(
+            owned.get(std::path::Path::new("random/other/skip.md")),
+            Some(&None)
+        );
+    }
+
+    #[test]
+    fn test_owners_for_tree_and_unowned() {
+        let dir = TempDir::new("owned-unowned");
+        dir.write("src/main.rs", "");
+        dir.write("README.md", "");
+        dir.write("vendor/deep/file.txt", "");
+
+        let codeowners = parse("/src/ @src-owner\n").unwrap();
+
+        let owned: std::collections::BTreeMap<_, _> =
+            codeowners.owners_for_tree(dir.path()).collect();
+        assert_eq!(
+            owned.get(std::path::Path::new("src/main.rs")),
+            Some(&Some([Handle("@src-owner".to_string())].as_slice()))
+        );
+        assert_eq!(owned.get(std::path::Path::new("README.md")), Some(&None));
+        // no rule reaches `vendor/`, so its files are reported unowned without being matched
+        assert_eq!(owned.get(std::path::Path::new("vendor/deep/file.txt")), Some(&None));
+
+        let mut unowned: Vec<_> = codeowners.unowned(dir.path()).collect();
+        unowned.sort();
+        assert_eq!(
+            unowned,
+            vec![
+                std::path::PathBuf::from("README.md"),
+                std::path::PathBuf::from("vendor/deep/file.txt"),
+            ]
+        );
+    }
 }
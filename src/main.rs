@@ -25,9 +25,10 @@ pub async fn main() -> anyhow::Result<()> {
     )?;
     let repo = github.repo("NixOS", "nixpkgs");
 
-    let codeowners = Rc::new(parse(
-        std::str::from_utf8(&std::fs::read("./CODEOWNERS").unwrap()).unwrap()
-    ).unwrap());
+    let codeowners = Rc::new(
+        Codeowners::from_repo(std::path::Path::new("."))
+            .context("Failed to load CODEOWNERS")?,
+    );
 
     let pulls = repo.pulls();
     let mut pulls_stream = pulls